@@ -1,3 +1,4 @@
+use crate::loader::{ContentId, ContentLoader, FsContentLoader, LoadError, SourceKind};
 use crate::parser::Extractor;
 use fxhash::FxHashSet;
 use rayon::prelude::*;
@@ -6,6 +7,7 @@ use tracing::event;
 
 pub mod candidate;
 pub mod glob;
+pub mod loader;
 pub mod location;
 pub mod modifier;
 pub mod parser;
@@ -34,6 +36,7 @@ pub struct ChangedContent {
 pub enum IO {
     Sequential = 0b0001,
     Parallel = 0b0010,
+    Async = 0b0011,
 }
 
 impl From<u8> for IO {
@@ -41,6 +44,7 @@ impl From<u8> for IO {
         match item & 0b0011 {
             0b0001 => IO::Sequential,
             0b0010 => IO::Parallel,
+            0b0011 => IO::Async,
             _ => unimplemented!("Unknown 'IO' strategy"),
         }
     }
@@ -64,22 +68,66 @@ impl From<u8> for Parsing {
 
 pub fn parse_candidate_strings_from_files(changed_content: Vec<ChangedContent>) -> Vec<String> {
     init_tracing();
-    parse_all_blobs(read_all_files(changed_content))
+    parse_all_blobs(
+        read_all_files(changed_content, &FsContentLoader).expect("failed to read files"),
+    )
 }
 
 pub fn parse_candidate_strings(input: Vec<ChangedContent>, options: u8) -> Vec<String> {
     init_tracing();
 
     match (IO::from(options), Parsing::from(options)) {
-        (IO::Sequential, Parsing::Sequential) => parse_all_blobs_sync(read_all_files_sync(input)),
-        (IO::Sequential, Parsing::Parallel) => parse_all_blobs_sync(read_all_files(input)),
-        (IO::Parallel, Parsing::Sequential) => parse_all_blobs(read_all_files_sync(input)),
-        (IO::Parallel, Parsing::Parallel) => parse_all_blobs(read_all_files(input)),
+        (IO::Sequential, Parsing::Sequential) => parse_all_blobs_sync(
+            read_all_files_sync(input, &FsContentLoader).expect("failed to read files"),
+        ),
+        (IO::Sequential, Parsing::Parallel) => parse_all_blobs_sync(
+            read_all_files(input, &FsContentLoader).expect("failed to read files"),
+        ),
+        (IO::Parallel, Parsing::Sequential) => parse_all_blobs(
+            read_all_files_sync(input, &FsContentLoader).expect("failed to read files"),
+        ),
+        (IO::Parallel, Parsing::Parallel) => parse_all_blobs(
+            read_all_files(input, &FsContentLoader).expect("failed to read files"),
+        ),
+        (IO::Async, Parsing::Sequential) => parse_all_blobs_sync(read_all_files_async(input)),
+        (IO::Async, Parsing::Parallel) => parse_all_blobs(read_all_files_async(input)),
     }
 }
 
-#[tracing::instrument(skip(changed_content))]
-fn read_all_files(changed_content: Vec<ChangedContent>) -> Vec<Vec<u8>> {
+/// Same as [`parse_candidate_strings`], but resolves file-based
+/// [`ChangedContent`] through `loader` and returns a `LoadError` instead of
+/// panicking. When files are read in parallel and several loads fail, which
+/// of those errors is returned is not defined. Rejects `IO::Async`, which
+/// always reads the filesystem directly and can't route through a loader.
+pub fn parse_candidate_strings_with_loader(
+    input: Vec<ChangedContent>,
+    options: u8,
+    loader: &dyn ContentLoader,
+) -> Result<Vec<String>, LoadError> {
+    init_tracing();
+
+    match (IO::from(options), Parsing::from(options)) {
+        (IO::Async, _) => Err(LoadError::Unsupported(
+            "IO::Async always reads the filesystem directly and can't honor a custom ContentLoader; use IO::Sequential or IO::Parallel instead",
+        )),
+        (IO::Sequential, Parsing::Sequential) => {
+            Ok(parse_all_blobs_sync(read_all_files_sync(input, loader)?))
+        }
+        (IO::Sequential, Parsing::Parallel) => {
+            Ok(parse_all_blobs_sync(read_all_files(input, loader)?))
+        }
+        (IO::Parallel, Parsing::Sequential) => {
+            Ok(parse_all_blobs(read_all_files_sync(input, loader)?))
+        }
+        (IO::Parallel, Parsing::Parallel) => Ok(parse_all_blobs(read_all_files(input, loader)?)),
+    }
+}
+
+#[tracing::instrument(skip(changed_content, loader))]
+fn read_all_files(
+    changed_content: Vec<ChangedContent>,
+    loader: &dyn ContentLoader,
+) -> Result<Vec<Vec<u8>>, LoadError> {
     event!(
         tracing::Level::INFO,
         "Reading {:?} file(s)",
@@ -88,16 +136,15 @@ fn read_all_files(changed_content: Vec<ChangedContent>) -> Vec<Vec<u8>> {
 
     changed_content
         .into_par_iter()
-        .map(|c| match (c.file, c.content) {
-            (Some(file), None) => std::fs::read(file).unwrap(),
-            (None, Some(content)) => content.into_bytes(),
-            _ => Default::default(),
-        })
+        .map(|c| load_one(c, loader))
         .collect()
 }
 
-#[tracing::instrument(skip(changed_content))]
-fn read_all_files_sync(changed_content: Vec<ChangedContent>) -> Vec<Vec<u8>> {
+#[tracing::instrument(skip(changed_content, loader))]
+fn read_all_files_sync(
+    changed_content: Vec<ChangedContent>,
+    loader: &dyn ContentLoader,
+) -> Result<Vec<Vec<u8>>, LoadError> {
     event!(
         tracing::Level::INFO,
         "Reading {:?} file(s)",
@@ -106,14 +153,100 @@ fn read_all_files_sync(changed_content: Vec<ChangedContent>) -> Vec<Vec<u8>> {
 
     changed_content
         .into_iter()
-        .map(|c| match (c.file, c.content) {
-            (Some(file), None) => std::fs::read(file).unwrap(),
-            (None, Some(content)) => content.into_bytes(),
-            _ => Default::default(),
-        })
+        .map(|c| load_one(c, loader))
         .collect()
 }
 
+/// Reads every file concurrently on an async runtime instead of blocking a
+/// rayon worker thread per read. Requires `tokio` (`rt-multi-thread`, `fs`)
+/// and `futures` as crate dependencies.
+#[tracing::instrument(skip(changed_content))]
+fn read_all_files_async(changed_content: Vec<ChangedContent>) -> Vec<Vec<u8>> {
+    event!(
+        tracing::Level::INFO,
+        "Reading {:?} file(s)",
+        changed_content.len()
+    );
+
+    async_runtime().block_on(async {
+        let reads = changed_content.into_iter().map(|c| async move {
+            match (c.file, c.content) {
+                (Some(file), None) => match tokio::fs::read(&file).await {
+                    Ok(bytes) => normalize_to_utf8(bytes),
+                    Err(err) => {
+                        event!(tracing::Level::WARN, "Failed to read {:?}: {}", file, err);
+                        Default::default()
+                    }
+                },
+                (None, Some(content)) => content.into_bytes(),
+                _ => Default::default(),
+            }
+        });
+
+        futures::future::join_all(reads).await
+    })
+}
+
+fn async_runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: std::sync::OnceLock<tokio::runtime::Runtime> = std::sync::OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("failed to start the async IO runtime")
+    })
+}
+
+fn load_one(content: ChangedContent, loader: &dyn ContentLoader) -> Result<Vec<u8>, LoadError> {
+    let kind = SourceKind::for_extension(&content.extension);
+
+    match (content.file, content.content) {
+        (Some(file), None) => {
+            let id = ContentId(file);
+            Ok(normalize_to_utf8(loader.load(&id, kind)?))
+        }
+        (None, Some(content)) => Ok(content.into_bytes()),
+        _ => Ok(Default::default()),
+    }
+}
+
+/// Detects a UTF-8/UTF-16 BOM, or an un-BOM'd UTF-16 byte pattern, and
+/// transcodes to UTF-8. Content that already looks like UTF-8 is untouched.
+fn normalize_to_utf8(bytes: Vec<u8>) -> Vec<u8> {
+    match detect_encoding(&bytes) {
+        Encoding::Utf8Bom => bytes[3..].to_vec(),
+        Encoding::Utf16Le { bom } => decode_utf16(&bytes[if bom { 2 } else { 0 }..], u16::from_le_bytes),
+        Encoding::Utf16Be { bom } => decode_utf16(&bytes[if bom { 2 } else { 0 }..], u16::from_be_bytes),
+        Encoding::Utf8 => bytes,
+    }
+}
+
+enum Encoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le { bom: bool },
+    Utf16Be { bom: bool },
+}
+
+fn detect_encoding(bytes: &[u8]) -> Encoding {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, ..] => Encoding::Utf8Bom,
+        [0xFF, 0xFE, ..] => Encoding::Utf16Le { bom: true },
+        [0xFE, 0xFF, ..] => Encoding::Utf16Be { bom: true },
+        [b0, b1, ..] if *b0 != 0 && *b1 == 0 => Encoding::Utf16Le { bom: false },
+        [b0, b1, ..] if *b0 == 0 && *b1 != 0 => Encoding::Utf16Be { bom: false },
+        _ => Encoding::Utf8,
+    }
+}
+
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> Vec<u8> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|chunk| to_u16([chunk[0], chunk[1]]))
+        .collect();
+    String::from_utf16_lossy(&units).into_bytes()
+}
+
 #[tracing::instrument(skip(blobs))]
 fn parse_all_blobs(blobs: Vec<Vec<u8>>) -> Vec<String> {
     let input: Vec<_> = blobs.iter().map(|blob| &blob[..]).collect();
@@ -138,6 +271,79 @@ fn parse_all_blobs(blobs: Vec<Vec<u8>>) -> Vec<String> {
     result
 }
 
+#[cfg(test)]
+mod encoding_tests {
+    use super::*;
+
+    #[test]
+    fn passes_through_plain_utf8() {
+        let input = b"bg-red-500 flex".to_vec();
+        assert_eq!(normalize_to_utf8(input.clone()), input);
+    }
+
+    #[test]
+    fn strips_a_utf8_bom() {
+        let mut input = vec![0xEF, 0xBB, 0xBF];
+        input.extend_from_slice(b"bg-red-500");
+        assert_eq!(normalize_to_utf8(input), b"bg-red-500");
+    }
+
+    #[test]
+    fn decodes_utf16_le_with_bom() {
+        let mut input = vec![0xFF, 0xFE];
+        for unit in "bg-red-500".encode_utf16() {
+            input.extend_from_slice(&unit.to_le_bytes());
+        }
+        assert_eq!(normalize_to_utf8(input), b"bg-red-500");
+    }
+
+    #[test]
+    fn decodes_utf16_be_with_bom() {
+        let mut input = vec![0xFE, 0xFF];
+        for unit in "bg-red-500".encode_utf16() {
+            input.extend_from_slice(&unit.to_be_bytes());
+        }
+        assert_eq!(normalize_to_utf8(input), b"bg-red-500");
+    }
+
+    #[test]
+    fn decodes_utf16_le_without_a_bom() {
+        let mut input = Vec::new();
+        for unit in "flex".encode_utf16() {
+            input.extend_from_slice(&unit.to_le_bytes());
+        }
+        assert_eq!(normalize_to_utf8(input), b"flex");
+    }
+
+    #[test]
+    fn decodes_utf16_be_without_a_bom() {
+        // "flex" has no code unit >= 0x100, so every unit's high byte is 0 and
+        // goes first in big-endian order, landing on the `b0 == 0` branch.
+        let mut input = Vec::new();
+        for unit in "flex".encode_utf16() {
+            input.extend_from_slice(&unit.to_be_bytes());
+        }
+        assert_eq!(normalize_to_utf8(input), b"flex");
+    }
+
+    #[test]
+    fn leaves_blobs_shorter_than_two_bytes_alone() {
+        assert_eq!(normalize_to_utf8(vec![]), Vec::<u8>::new());
+        assert_eq!(normalize_to_utf8(vec![b'a']), vec![b'a']);
+    }
+
+    #[test]
+    fn drops_a_trailing_odd_byte_when_decoding_utf16() {
+        // chunks_exact(2) silently ignores a dangling final byte; pin that
+        // behavior rather than let it regress into a panic or data loss
+        // surprise.
+        let mut input = vec![0xFF, 0xFE];
+        input.extend_from_slice(&('a' as u16).to_le_bytes());
+        input.push(0x42);
+        assert_eq!(normalize_to_utf8(input), b"a");
+    }
+}
+
 #[tracing::instrument(skip(blobs))]
 fn parse_all_blobs_sync(blobs: Vec<Vec<u8>>) -> Vec<String> {
     let input: Vec<_> = blobs.iter().map(|blob| &blob[..]).collect();
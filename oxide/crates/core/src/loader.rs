@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+/// Identifies a piece of content a [`ContentLoader`] is asked to resolve.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContentId(pub PathBuf);
+
+/// Distinguishes scannable templates from embedded assets a loader may want
+/// to skip entirely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    Scannable,
+    Embedded,
+}
+
+impl SourceKind {
+    /// Classifies a file by its extension.
+    pub fn for_extension(extension: &str) -> SourceKind {
+        const EMBEDDED_EXTENSIONS: &[&str] = &[
+            "png", "jpg", "jpeg", "gif", "webp", "avif", "ico", "bmp", "woff", "woff2", "ttf",
+            "otf", "eot",
+        ];
+
+        if EMBEDDED_EXTENSIONS.contains(&extension.to_lowercase().as_str()) {
+            SourceKind::Embedded
+        } else {
+            SourceKind::Scannable
+        }
+    }
+}
+
+/// A load failure surfaced as a value instead of a panic: an IO error from a
+/// [`ContentLoader`], or options that can't use a loader at all.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(std::io::Error),
+    /// The requested options can't honor a `ContentLoader` at all.
+    Unsupported(&'static str),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "failed to load content: {err}"),
+            LoadError::Unsupported(reason) => write!(f, "unsupported content loader: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(err: std::io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+/// Resolves the raw bytes behind a [`ContentId`].
+pub trait ContentLoader: Send + Sync {
+    fn load(&self, id: &ContentId, kind: SourceKind) -> Result<Vec<u8>, LoadError>;
+}
+
+/// The default loader: reads templates straight off disk.
+#[derive(Debug, Default)]
+pub struct FsContentLoader;
+
+impl ContentLoader for FsContentLoader {
+    fn load(&self, id: &ContentId, kind: SourceKind) -> Result<Vec<u8>, LoadError> {
+        match kind {
+            SourceKind::Embedded => Ok(Default::default()),
+            SourceKind::Scannable => Ok(std::fs::read(&id.0)?),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use fxhash::FxHashSet;
+
+/// Maximum edit distance considered a plausible typo. Beyond this the
+/// "did you mean" pass stays silent rather than guessing.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// A candidate that didn't resolve to a known utility, paired with the
+/// closest recognized root within [`MAX_SUGGESTION_DISTANCE`] edits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub candidate: String,
+    pub suggestion: String,
+}
+
+/// Flags extracted candidates that are one or two edits away from a known
+/// utility root but didn't match anything (`flexx`, `justfy-center`).
+pub fn suggest_near_misses(candidates: &[String], known_roots: &FxHashSet<String>) -> Vec<Suggestion> {
+    candidates
+        .iter()
+        .filter_map(|candidate| {
+            let root = utility_root(candidate);
+
+            // `bg-red-500/` resolves to a valid root, so it never reaches
+            // closest_root below; surface the un-mangled root instead.
+            if has_empty_modifier(candidate) && known_roots.contains(root) {
+                return Some(Suggestion {
+                    candidate: candidate.clone(),
+                    suggestion: root.to_string(),
+                });
+            }
+
+            if known_roots.contains(root) {
+                return None;
+            }
+
+            closest_root(root, known_roots).map(|suggestion| Suggestion {
+                candidate: candidate.clone(),
+                suggestion,
+            })
+        })
+        .collect()
+}
+
+/// Strips variant prefixes (`hover:`, `lg:`, …) and a modifier (`/50`).
+fn utility_root(candidate: &str) -> &str {
+    let without_variants = candidate.rsplit(':').next().unwrap_or(candidate);
+    match without_variants.split_once('/') {
+        Some((root, _)) => root,
+        None => without_variants,
+    }
+}
+
+/// True for a candidate like `bg-red-500/` with an empty modifier.
+fn has_empty_modifier(candidate: &str) -> bool {
+    let without_variants = candidate.rsplit(':').next().unwrap_or(candidate);
+    matches!(without_variants.split_once('/'), Some((_, "")))
+}
+
+fn closest_root(candidate: &str, known_roots: &FxHashSet<String>) -> Option<String> {
+    let candidate = candidate.to_lowercase();
+
+    known_roots
+        .iter()
+        .filter_map(|root| {
+            let distance = levenshtein(&candidate, &root.to_lowercase(), MAX_SUGGESTION_DISTANCE)?;
+            Some((distance, root))
+        })
+        // Break ties by root string; FxHashSet iteration order isn't stable.
+        .min_by(|(d1, r1), (d2, r2)| d1.cmp(d2).then_with(|| r1.cmp(r2)))
+        .map(|(_, root)| root.clone())
+}
+
+/// Two-row DP Levenshtein distance, `O(n*m)` time, `O(min(n, m))` space.
+/// Bails out early once the current row's minimum exceeds `max_distance`.
+fn levenshtein(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // Size the rows by the shorter string; the distance is symmetric.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for (i, &ca) in a.iter().enumerate() {
+        current_row[0] = i + 1;
+        let mut row_min = current_row[0];
+
+        for (j, &cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            current_row[j + 1] = (previous_row[j] + cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+            row_min = row_min.min(current_row[j + 1]);
+        }
+
+        if row_min > max_distance {
+            return None;
+        }
+
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    let distance = previous_row[b.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(values: &[&str]) -> FxHashSet<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn flags_near_miss_typos() {
+        let known = roots(&["flex", "justify-center"]);
+        let candidates = vec!["flexx".to_string(), "justfy-center".to_string()];
+
+        let suggestions = suggest_near_misses(&candidates, &known);
+
+        assert_eq!(
+            suggestions,
+            vec![
+                Suggestion {
+                    candidate: "flexx".to_string(),
+                    suggestion: "flex".to_string(),
+                },
+                Suggestion {
+                    candidate: "justfy-center".to_string(),
+                    suggestion: "justify-center".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn flags_trailing_slash_with_no_modifier() {
+        let known = roots(&["bg-red-500"]);
+        let candidates = vec!["bg-red-500/".to_string()];
+
+        let suggestions = suggest_near_misses(&candidates, &known);
+
+        assert_eq!(
+            suggestions,
+            vec![Suggestion {
+                candidate: "bg-red-500/".to_string(),
+                suggestion: "bg-red-500".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn does_not_flag_a_real_modifier_or_a_valid_candidate() {
+        let known = roots(&["bg-red-500"]);
+        let candidates = vec!["bg-red-500/50".to_string(), "bg-red-500".to_string()];
+
+        assert!(suggest_near_misses(&candidates, &known).is_empty());
+    }
+
+    #[test]
+    fn breaks_ties_deterministically() {
+        // "car" and "bat" are each a single edit away from "cat".
+        let known = roots(&["car", "bat"]);
+        let candidates = vec!["cat".to_string()];
+
+        let suggestions = suggest_near_misses(&candidates, &known);
+
+        assert_eq!(suggestions[0].suggestion, "bat");
+    }
+
+    #[test]
+    fn levenshtein_respects_the_cap() {
+        assert_eq!(levenshtein("flex", "flexx", 2), Some(1));
+        assert_eq!(levenshtein("flex", "block", 2), None);
+    }
+}